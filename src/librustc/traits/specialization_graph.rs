@@ -0,0 +1,35 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Construction of the specialization graph for a trait, along with the
+//! types callers use to describe how two impls relate to one another.
+
+/// Whether two impls -- either both inherent impls of the same type, or
+/// both impls of the same trait -- can apply to the same concrete type,
+/// and, if so, whether one of them is permitted to specialize the other.
+///
+/// This lives here, rather than in `librustc_typeck`, because it is the
+/// return type of the `impls_overlap` query: queries and the types they
+/// return have to live in the same crate as `Providers` itself, so that
+/// downstream crates (clippy, rustdoc, lint passes) can name the type
+/// without depending on `librustc_typeck`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OverlapKind {
+    /// The impls can never apply to the same type.
+    Disjoint,
+    /// The impls overlap, but the overlap is permitted because one of
+    /// them specializes the other.
+    Permitted,
+    /// The impls overlap and neither specializes the other. For trait
+    /// impls this is a coherence violation; overlapping inherent impls
+    /// conflict only if they define items with the same name, which is
+    /// not checked here.
+    Forbidden,
+}
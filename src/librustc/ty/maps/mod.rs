@@ -0,0 +1,27 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use hir::def_id::DefId;
+use traits::OverlapKind;
+
+// The other `Providers` fields used by `librustc_typeck::coherence`
+// (`coherent_trait`, `crate_inherent_impls`, `inherent_impls`,
+// `crate_inherent_impls_overlap_check`, `coerce_unsized_info`, ...) are
+// declared alongside the rest of the query system; only the addition is
+// shown here.
+define_maps! { <'tcx>
+    /// Determines whether two impls -- either both inherent impls of the
+    /// same type, or both impls of the same trait -- can apply to the
+    /// same concrete type, and if so, whether one specializes the other.
+    /// Unlike `coherent_trait`, this performs no diagnostic reporting of
+    /// its own; it exists so that clippy, rustdoc, and lint passes can
+    /// reuse the disjointness check directly.
+    [] fn impls_overlap: ImplsOverlap((DefId, DefId)) -> OverlapKind,
+}
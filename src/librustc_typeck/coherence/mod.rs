@@ -17,6 +17,7 @@
 
 use hir::def_id::{DefId, LOCAL_CRATE};
 use rustc::traits;
+use rustc::traits::OverlapKind;
 use rustc::ty::{self, TyCtxt, TypeFoldable};
 use rustc::ty::maps::Providers;
 
@@ -110,10 +111,55 @@ pub fn provide(providers: &mut Providers) {
         inherent_impls,
         crate_inherent_impls_overlap_check,
         coerce_unsized_info,
+        impls_overlap,
         ..*providers
     };
 }
 
+/// Whether `impl1_def_id` and `impl2_def_id` overlap, and if so whether
+/// specialization permits it, for callers (clippy, rustdoc) that need
+/// this without running full coherence checking. Always computed
+/// pairwise rather than via `tcx.specialization_graph_of`, whose graph
+/// can be missing impls inserted after the first conflict it found.
+fn impls_overlap<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                           (impl1_def_id, impl2_def_id): (DefId, DefId))
+                           -> OverlapKind {
+    let trait1_ref = tcx.impl_trait_ref(impl1_def_id);
+    let trait2_ref = tcx.impl_trait_ref(impl2_def_id);
+
+    match (trait1_ref, trait2_ref) {
+        // Two impls of the same trait.
+        (Some(trait1_ref), Some(trait2_ref)) => {
+            if trait1_ref.def_id != trait2_ref.def_id {
+                return OverlapKind::Disjoint;
+            }
+
+            if !traits::overlapping_impls(tcx, impl1_def_id, impl2_def_id) {
+                OverlapKind::Disjoint
+            } else if tcx.specializes((impl1_def_id, impl2_def_id)) ||
+                      tcx.specializes((impl2_def_id, impl1_def_id)) {
+                OverlapKind::Permitted
+            } else {
+                OverlapKind::Forbidden
+            }
+        }
+
+        // Two inherent impls: overlap is purely a question of whether
+        // the self types can unify, and there is no specialization to
+        // permit it.
+        (None, None) => {
+            if traits::overlapping_impls(tcx, impl1_def_id, impl2_def_id) {
+                OverlapKind::Forbidden
+            } else {
+                OverlapKind::Disjoint
+            }
+        }
+
+        // One inherent, one trait impl: these never overlap.
+        (Some(_), None) | (None, Some(_)) => OverlapKind::Disjoint,
+    }
+}
+
 fn coherent_trait<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, def_id: DefId) {
     let impls = tcx.hir.trait_impls(def_id);
     for &impl_id in impls {
@@ -122,9 +168,177 @@ fn coherent_trait<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, def_id: DefId) {
     for &impl_id in impls {
         check_impl_overlap(tcx, impl_id);
     }
+    report_all_overlaps(tcx, def_id);
     builtin::check_trait(tcx, def_id);
 }
 
+/// Reports, in one grouped note, every forbidden overlap among the impls
+/// of `trait_def_id`, since `specialization_graph_of`'s hard error stops
+/// at the first conflict it finds. The note lists the pair that error
+/// already reported along with the rest; a lone conflict gets a note only
+/// when there is a specialization suggestion to add. Bucketed the same way
+/// `specialization_graph_of` buckets impls, to avoid an all-pairs scan
+/// over every impl of a widely-implemented trait.
+fn report_all_overlaps<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, trait_def_id: DefId) {
+    let all_impls = tcx.trait_impls_of(trait_def_id);
+    let total_impls = all_impls.blanket_impls.len() +
+        all_impls.non_blanket_impls.values().map(|impls| impls.len()).sum::<usize>();
+
+    if total_impls < 2 {
+        return;
+    }
+
+    let mut candidate_pairs = Vec::new();
+
+    // Non-blanket impls whose self types simplify to different buckets
+    // can never unify, so only pair up impls within the same bucket.
+    for impls in all_impls.non_blanket_impls.values() {
+        for (i, &impl_a) in impls.iter().enumerate() {
+            for &impl_b in &impls[i + 1..] {
+                candidate_pairs.push((impl_a, impl_b));
+            }
+        }
+    }
+
+    // A blanket impl can apply to any self type, so it has to be checked
+    // against every non-blanket impl and every other blanket impl.
+    for (i, &impl_a) in all_impls.blanket_impls.iter().enumerate() {
+        for &impl_b in &all_impls.blanket_impls[i + 1..] {
+            candidate_pairs.push((impl_a, impl_b));
+        }
+        for impls in all_impls.non_blanket_impls.values() {
+            candidate_pairs.extend(impls.iter().map(|&impl_b| (impl_a, impl_b)));
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    for (impl_a, impl_b) in candidate_pairs {
+        // A conflict between two foreign impls is the other crate's
+        // problem: it would have already failed to compile there.
+        if !impl_a.is_local() && !impl_b.is_local() {
+            continue;
+        }
+
+        if tcx.impls_overlap((impl_a, impl_b)) == OverlapKind::Forbidden {
+            conflicts.push((impl_a, impl_b));
+        }
+    }
+
+    if conflicts.is_empty() {
+        return;
+    }
+
+    // `non_blanket_impls` buckets by an `FxHashMap`, so the order in which
+    // conflicts were discovered above depends on that map's iteration
+    // order, not on declaration order. Sort them into a fixed order before
+    // picking an anchor or reporting a count, so the diagnostic is
+    // deterministic regardless of hashing, and so this function never has
+    // to assume its ordering lines up with whatever pair
+    // `specialization_graph_of` happened to insert (and report) first.
+    conflicts.sort();
+
+    // The lone-conflict case is already fully covered by
+    // `specialization_graph_of`'s own error; only add a note if we have a
+    // suggestion to offer that the bare error doesn't carry.
+    let suggestion = if conflicts.len() == 1 {
+        sole_differing_bound(tcx, conflicts[0].0, conflicts[0].1)
+    } else {
+        None
+    };
+    if conflicts.len() == 1 && suggestion.is_none() {
+        return;
+    }
+
+    // Anchor the note on one of the impls in the (now deterministically
+    // ordered) first conflicting pair, preferring the local one, rather
+    // than on the trait definition, so it reads as a comment on a
+    // specific overlap rather than a trait-wide summary when there's
+    // only one pair to report. When there's exactly one conflict this is
+    // unambiguously the same pair `specialization_graph_of` already
+    // reported as a hard error, since there's nothing else it could be;
+    // with more than one conflict, nothing here claims this is the exact
+    // pair that produced that hard error, only that it's one of the
+    // pairs still needing a fix, so the message below doesn't promise
+    // that alignment either.
+    let (anchor_a, anchor_b) = conflicts[0];
+    let anchor_sp = if anchor_b.is_local() {
+        tcx.sess.codemap().def_span(tcx.span_of_impl(anchor_b).unwrap())
+    } else if anchor_a.is_local() {
+        tcx.sess.codemap().def_span(tcx.span_of_impl(anchor_a).unwrap())
+    } else {
+        tcx.def_span(trait_def_id)
+    };
+
+    let message = if conflicts.len() == 1 {
+        format!("this impl overlaps with another impl of trait `{}`",
+                tcx.item_path_str(trait_def_id))
+    } else {
+        format!("trait `{}` has {} overlapping impl pairs in total; one of them is already \
+                 reported as a hard error above, and all of them (including that one) are \
+                 listed here so they can all be fixed at once",
+                tcx.item_path_str(trait_def_id),
+                conflicts.len())
+    };
+    let mut note = tcx.sess.diagnostic().span_note_without_error(anchor_sp, &message);
+    // Emitted at most once: every `default impl` suggestion below needs the
+    // same crate-level feature gate, so suggesting it again for each pair
+    // would just be noise.
+    let mut suggested_enabling_specialization = false;
+
+    for &(impl_a, impl_b) in &conflicts {
+        for &impl_of_pair in &[impl_a, impl_b] {
+            if impl_of_pair.is_local() {
+                let sp = tcx.sess.codemap().def_span(tcx.span_of_impl(impl_of_pair).unwrap());
+                note.span_label(sp, "conflicting implementation");
+            } else {
+                let cname = tcx.sess.cstore.crate_name(impl_of_pair.krate);
+                note.note(&format!("conflicting implementation in crate `{}`: `{}`",
+                                   cname,
+                                   tcx.item_path_str(impl_of_pair)));
+            }
+        }
+
+        let pair_suggestion = if conflicts.len() == 1 {
+            suggestion.clone()
+        } else {
+            sole_differing_bound(tcx, impl_a, impl_b)
+        };
+        if let Some((more_general, sole_extra_bound)) = pair_suggestion {
+            if more_general.is_local() {
+                let general_sp =
+                    tcx.sess.codemap().def_span(tcx.span_of_impl(more_general).unwrap());
+                if let Ok(snippet) = tcx.sess.codemap().span_to_snippet(general_sp) {
+                    note.span_suggestion(general_sp,
+                                          &format!("this impl lacks the `{}` bound the other \
+                                                    has; consider specializing it under \
+                                                    `#![feature(specialization)]`",
+                                                   sole_extra_bound),
+                                          format!("default {}", snippet));
+
+                    // The suggestion above only compiles once specialization
+                    // itself is enabled, so on a crate that hasn't already
+                    // turned it on, suggest adding the feature gate too --
+                    // otherwise applying just the `default impl` edit would
+                    // trade this coherence error for an unstable-feature
+                    // error instead of actually fixing the build.
+                    if !tcx.features().specialization && !suggested_enabling_specialization {
+                        let krate_span = tcx.hir.krate().span;
+                        let start_of_krate = krate_span.with_hi(krate_span.lo());
+                        note.span_suggestion(start_of_krate,
+                                              "specialization is not enabled for this crate; \
+                                               add this at the crate root (even if the impl \
+                                               above is in a submodule)",
+                                              "#![feature(specialization)]\n".to_string());
+                        suggested_enabling_specialization = true;
+                    }
+                }
+            }
+        }
+    }
+
+    note.emit();
+}
+
 pub fn check_coherence<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>) {
     for &trait_def_id in tcx.hir.krate().trait_impls.keys() {
         ty::maps::queries::coherent_trait::ensure(tcx, trait_def_id);
@@ -153,7 +367,10 @@ fn check_impl_overlap<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, node_id: ast::NodeI
     }
 
     // Trigger building the specialization graph for the trait of this impl.
-    // This will detect any overlap errors.
+    // This will detect any overlap errors. A specialization suggestion for
+    // the conflict, if one applies, is attached separately by
+    // `report_all_overlaps` in `coherent_trait`, since this function has no
+    // diagnostic of its own to attach a `span_suggestion` to.
     tcx.specialization_graph_of(trait_def_id);
 
     // check for overlap with the automatic `impl Trait for Trait`
@@ -169,7 +386,8 @@ fn check_impl_overlap<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, node_id: ast::NodeI
                 traits::supertrait_def_ids(tcx,
                                            data.principal().unwrap().def_id());
             if supertrait_def_ids.any(|d| d == trait_def_id) {
-                let sp = tcx.sess.codemap().def_span(tcx.span_of_impl(impl_def_id).unwrap());
+                let full_sp = tcx.span_of_impl(impl_def_id).unwrap();
+                let sp = tcx.sess.codemap().def_span(full_sp);
                 struct_span_err!(tcx.sess,
                                  sp,
                                  E0371,
@@ -179,8 +397,34 @@ fn check_impl_overlap<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, node_id: ast::NodeI
                     .span_label(sp, format!("`{}` automatically implements trait `{}`",
                                             trait_ref.self_ty(),
                                             tcx.item_path_str(trait_def_id)))
+                    .span_suggestion(full_sp,
+                                      "consider removing this redundant impl, since the \
+                                       object type already implements the trait",
+                                      String::new())
                     .emit();
             }
         }
     }
 }
+
+/// If `a` and `b` are impls of the same trait whose predicates differ by
+/// exactly one bound, returns the more general of the two impls (the one
+/// *without* the extra bound) along with a description of that bound.
+fn sole_differing_bound<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                  a: DefId,
+                                  b: DefId)
+                                  -> Option<(DefId, String)> {
+    let preds_a = &tcx.predicates_of(a).predicates;
+    let preds_b = &tcx.predicates_of(b).predicates;
+
+    let extra_in_a: Vec<_> = preds_a.iter().filter(|p| !preds_b.contains(p)).collect();
+    let extra_in_b: Vec<_> = preds_b.iter().filter(|p| !preds_a.contains(p)).collect();
+
+    if extra_in_a.len() == 1 && extra_in_b.is_empty() {
+        Some((b, extra_in_a[0].to_string()))
+    } else if extra_in_b.len() == 1 && extra_in_a.is_empty() {
+        Some((a, extra_in_b[0].to_string()))
+    } else {
+        None
+    }
+}
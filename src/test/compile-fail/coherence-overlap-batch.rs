@@ -0,0 +1,27 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Three mutually-overlapping impls of the same trait: `specialization_graph_of`
+// reports only the first conflicting pair it hits while building the graph,
+// so check that the two remaining pairs are also surfaced, in one grouped note,
+// instead of requiring a second recompile to discover it.
+
+trait Marker {}
+
+struct Foo;
+
+impl Marker for Foo {}
+impl Marker for Foo {}
+//~^ ERROR E0119
+//~| NOTE overlapping impl pairs in total
+
+impl Marker for Foo {}
+
+fn main() {}
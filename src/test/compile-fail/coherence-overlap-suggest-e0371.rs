@@ -0,0 +1,21 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// The object type already implements `Foo`, so this impl is
+// redundant; check that the suggestion to remove it is offered.
+
+trait Foo {}
+
+impl Foo for Foo { }
+//~^ ERROR E0371
+//~| NOTE automatically implements
+//~| HELP consider removing this redundant impl
+
+fn main() {}
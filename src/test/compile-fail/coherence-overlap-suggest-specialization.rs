@@ -0,0 +1,28 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// These two impls overlap and differ by exactly one bound, but this
+// crate doesn't have `#![feature(specialization)]` enabled; check that
+// the suggestion to rewrite the less-bounded impl as a `default impl`
+// comes paired with a suggestion to actually enable the feature,
+// instead of handing out an edit that would just trade this error for
+// an unstable-feature one.
+
+trait Marker {}
+//~^ HELP specialization is not enabled for this crate
+
+impl<T> Marker for T {}
+//~^ HELP this impl lacks the
+
+impl<T: Clone> Marker for T {}
+//~^ ERROR E0119
+//~| NOTE this impl overlaps with another impl of trait `Marker`
+
+fn main() {}
@@ -0,0 +1,37 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// These two impls overlap, but the overlap is permitted because the
+// `i32` impl specializes the blanket one. report_all_overlaps must
+// treat this as `OverlapKind::Permitted`, not `Forbidden`, and emit no
+// diagnostic at all.
+
+#![feature(specialization)]
+
+trait Marker {
+    fn describe() -> &'static str;
+}
+
+impl<T> Marker for T {
+    default fn describe() -> &'static str {
+        "generic"
+    }
+}
+
+impl Marker for i32 {
+    fn describe() -> &'static str {
+        "i32"
+    }
+}
+
+fn main() {
+    assert_eq!(<i32 as Marker>::describe(), "i32");
+    assert_eq!(<u8 as Marker>::describe(), "generic");
+}